@@ -0,0 +1,32 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ReviewError {
+    #[error("Account not initialized yet")]
+    UninitializedAccount,
+
+    #[error("PDA derived does not equal PDA passed in")]
+    InvalidPDA,
+
+    #[error("Input data exceeds max length")]
+    InvalidDataLength,
+
+    #[error("Rating greater than 5 or less than 1")]
+    InvalidRating,
+
+    #[error("Account is not rent exempt")]
+    NotRentExempt,
+
+    #[error("Account discriminator does not match expected account type")]
+    InvalidAccountType,
+
+    #[error("Signer is not the review's current authority")]
+    IncorrectAuthority,
+}
+
+impl From<ReviewError> for ProgramError {
+    fn from(e: ReviewError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
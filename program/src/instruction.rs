@@ -0,0 +1,89 @@
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+pub enum MovieInstruction {
+    AddMovieReview {
+        title: String,
+        rating: u8,
+        description: String,
+    },
+    UpdateMovieReview {
+        rating: u8,
+        description: String,
+    },
+    CloseMovieReview,
+    AddComment {
+        comment: String,
+    },
+    SetAuthority {
+        new_authority: Pubkey,
+    },
+}
+
+#[derive(BorshDeserialize)]
+struct MovieReviewPayload {
+    title: String,
+    rating: u8,
+    description: String,
+}
+
+#[derive(BorshDeserialize)]
+struct UpdateMovieReviewPayload {
+    rating: u8,
+    description: String,
+}
+
+#[derive(BorshDeserialize)]
+struct AddCommentPayload {
+    comment: String,
+}
+
+#[derive(BorshDeserialize)]
+struct SetAuthorityPayload {
+    new_authority: Pubkey,
+}
+
+impl MovieInstruction {
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&variant, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        Ok(match variant {
+            0 => {
+                let payload = MovieReviewPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::AddMovieReview {
+                    title: payload.title,
+                    rating: payload.rating,
+                    description: payload.description,
+                }
+            }
+            1 => {
+                let payload = UpdateMovieReviewPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::UpdateMovieReview {
+                    rating: payload.rating,
+                    description: payload.description,
+                }
+            }
+            2 => Self::CloseMovieReview,
+            3 => {
+                let payload = AddCommentPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::AddComment {
+                    comment: payload.comment,
+                }
+            }
+            4 => {
+                let payload = SetAuthorityPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::SetAuthority {
+                    new_authority: payload.new_authority,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
@@ -1,13 +1,16 @@
 use crate::error::ReviewError;
 use crate::instruction::MovieInstruction;
-use crate::state::{MovieAccountState};
+use crate::state::{
+    MovieAccountState, MovieComment, MovieCommentCounter, MOVIE_ACCOUNT_DISCRIMINATOR,
+    MOVIE_COMMENT_COUNTER_DISCRIMINATOR, MOVIE_COMMENT_DISCRIMINATOR,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_pack::IsInitialized;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
-    entrypoint::ProgramResult,
+    entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
     msg,
-    program::invoke_signed,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
@@ -27,6 +30,14 @@ pub fn process_instruction(
             rating,
             description,
         } => add_movie_review(program_id, accounts, title, rating, description),
+        MovieInstruction::UpdateMovieReview { rating, description } => {
+            update_movie_review(program_id, accounts, rating, description)
+        }
+        MovieInstruction::CloseMovieReview => close_movie_review(program_id, accounts),
+        MovieInstruction::AddComment { comment } => add_comment(program_id, accounts, comment),
+        MovieInstruction::SetAuthority { new_authority } => {
+            set_authority(program_id, accounts, new_authority)
+        }
     }
 }
 
@@ -46,6 +57,7 @@ pub fn add_movie_review(
     let account_info_iter = &mut accounts.iter();
     let initializer = next_account_info(account_info_iter)?;
     let pda_account = next_account_info(account_info_iter)?;
+    let pda_counter = next_account_info(account_info_iter)?;
     let system_program = next_account_info(account_info_iter)?;
 
     // Validate signer
@@ -64,10 +76,23 @@ pub fn add_movie_review(
         return Err(ReviewError::InvalidPDA.into());
     }
 
+    // Derive and validate the comment counter PDA
+    let (counter_pda, counter_bump) =
+        Pubkey::find_program_address(&[pda.as_ref(), b"comment"], program_id);
+    if counter_pda != *pda_counter.key {
+        msg!("Invalid seeds for counter PDA");
+        return Err(ReviewError::InvalidPDA.into());
+    }
+
     // Check if account already exists and is initialized
     if pda_account.data_is_empty() {
         msg!("Account does not exist. Creating new account...");
     } else {
+        // A pre-funded account must already be owned by this program before we trust its data
+        if pda_account.owner != program_id {
+            msg!("Account already funded but not owned by this program");
+            return Err(ProgramError::IllegalOwner);
+        }
         // Try to deserialize the account data to check if it's initialized
         match MovieAccountState::try_from_slice(&pda_account.data.borrow()) {
             Ok(account_data) => {
@@ -89,12 +114,8 @@ pub fn add_movie_review(
         return Err(ReviewError::InvalidRating.into());
     }
 
-    // Rest of the function remains the same...
-    let account_len: usize = 1000;
-    let total_len: usize = MovieAccountState::get_account_size(title.clone(), description.clone());
-    if total_len > account_len {
-        return Err(ReviewError::InvalidDataLength.into());
-    }
+    // Allocate exactly as much space as this review needs
+    let account_len: usize = MovieAccountState::get_account_size(title.clone(), description.clone());
 
     let rent = Rent::get()?;
     let rent_lamports = rent.minimum_balance(account_len);
@@ -122,8 +143,10 @@ pub fn add_movie_review(
 
     // Create new account data
     let account_data = MovieAccountState {
+        discriminator: MOVIE_ACCOUNT_DISCRIMINATOR,
         is_initialized: true,
         reviewer: *initializer.key,
+        authority: *initializer.key,
         rating,
         title,
         description,
@@ -134,49 +157,69 @@ pub fn add_movie_review(
     account_data.serialize(&mut &mut pda_account.data.borrow_mut()[..])?;
     msg!("State account serialized");
 
+    // Create the comment counter account for this review
+    msg!("Creating comment counter");
+    let counter_rent_lamports = rent.minimum_balance(MovieCommentCounter::SIZE);
+    invoke_signed(
+        &system_instruction::create_account(
+            initializer.key,
+            pda_counter.key,
+            counter_rent_lamports,
+            MovieCommentCounter::SIZE.try_into().unwrap(),
+            program_id,
+        ),
+        &[
+            initializer.clone(),
+            pda_counter.clone(),
+            system_program.clone(),
+        ],
+        &[&[pda.as_ref(), b"comment", &[counter_bump]]],
+    )?;
+    msg!("Comment counter created");
+
+    let counter_data = MovieCommentCounter {
+        discriminator: MOVIE_COMMENT_COUNTER_DISCRIMINATOR,
+        is_initialized: true,
+        counter: 0,
+    };
+    counter_data.serialize(&mut &mut pda_counter.data.borrow_mut()[..])?;
+    msg!("Comment counter initialized");
+
     Ok(())
 }
 
 pub fn update_movie_review(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    title: String,
     rating: u8,
     description: String,
 ) -> ProgramResult {
     msg!("Updating movie review...");
 
     let account_info_iter = &mut accounts.iter();
-    let initializer = next_account_info(account_info_iter)?;
+    let authority = next_account_info(account_info_iter)?;
     let pda_account = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
 
-    // Validate account ownership and signer
+    // Validate account ownership
     if pda_account.owner != program_id {
         return Err(ProgramError::IllegalOwner);
     }
-    if !initializer.is_signer {
-        msg!("Missing required signature");
-        return Err(ProgramError::MissingRequiredSignature);
-    }
 
     // Deserialize account data
     msg!("Unpacking state account");
     let mut account_data = MovieAccountState::try_from_slice(&pda_account.data.borrow())?;
     msg!("Review title: {}", account_data.title);
 
-    // Validate PDA
-    let (pda, _bump_seed) = Pubkey::find_program_address(
-        &[
-            initializer.key.as_ref(),
-            account_data.title.as_bytes().as_ref(),
-        ],
-        program_id,
-    );
-    if pda != *pda_account.key {
-        msg!("Invalid seeds for PDA");
-        return Err(ReviewError::InvalidPDA.into());
+    // Guard against a foreign account type being passed off as a review
+    if account_data.discriminator != MOVIE_ACCOUNT_DISCRIMINATOR {
+        msg!("Account is not a movie review");
+        return Err(ReviewError::InvalidAccountType.into());
     }
 
+    // Only the review's current authority may update it
+    check_authority(authority, &account_data.authority)?;
+
     // Check account initialization
     msg!("Checking if movie account is initialized");
     if !account_data.is_initialized() {
@@ -190,12 +233,31 @@ pub fn update_movie_review(
         return Err(ReviewError::InvalidRating.into());
     }
 
-    // Check data length
-    let update_len = MovieAccountState::get_account_size(title, description.clone());
-    if update_len > 1000 {
-        msg!("Data length is larger than 1000 bytes");
-        return Err(ReviewError::InvalidDataLength.into());
+    // Title is an immutable PDA seed; size using the stored title, not caller input
+    let update_len = MovieAccountState::get_account_size(account_data.title.clone(), description.clone());
+    let current_len = pda_account.data_len();
+    if update_len > current_len {
+        let len_increase = update_len - current_len;
+        if len_increase > MAX_PERMITTED_DATA_INCREASE {
+            msg!("Update would increase account size beyond the permitted limit");
+            return Err(ReviewError::InvalidDataLength.into());
+        }
+
+        let rent = Rent::get()?;
+        let new_minimum_balance = rent.minimum_balance(update_len);
+        let lamports_diff = new_minimum_balance.saturating_sub(pda_account.lamports());
+        if lamports_diff > 0 {
+            invoke(
+                &system_instruction::transfer(authority.key, pda_account.key, lamports_diff),
+                &[
+                    authority.clone(),
+                    pda_account.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
     }
+    pda_account.realloc(update_len, false)?;
 
     // Log review details before update
     msg!("Review before update:");
@@ -213,10 +275,201 @@ pub fn update_movie_review(
     msg!("Rating: {}", account_data.rating);
     msg!("Description: {}", account_data.description);
 
+    // Rent exemption must hold before we persist the update
+    let rent = Rent::get()?;
+    if !rent.is_exempt(pda_account.lamports(), pda_account.data_len()) {
+        msg!("Account is not rent exempt");
+        return Err(ReviewError::NotRentExempt.into());
+    }
+
     // Serialize updated account data
     msg!("Serializing account");
     account_data.serialize(&mut &mut pda_account.data.borrow_mut()[..])?;
     msg!("State account serialized");
 
+    Ok(())
+}
+
+pub fn close_movie_review(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Closing movie review...");
+
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let pda_account = next_account_info(account_info_iter)?;
+    let pda_counter = next_account_info(account_info_iter)?;
+
+    // Validate account ownership
+    if pda_account.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Guard against a foreign account type being passed off as a review
+    let account_data = MovieAccountState::try_from_slice(&pda_account.data.borrow())?;
+    if account_data.discriminator != MOVIE_ACCOUNT_DISCRIMINATOR {
+        msg!("Account is not a movie review");
+        return Err(ReviewError::InvalidAccountType.into());
+    }
+
+    // Only the review's current authority may close it
+    check_authority(authority, &account_data.authority)?;
+
+    // Validate the comment counter PDA that add_movie_review created alongside this review
+    let (counter_pda, _counter_bump) =
+        Pubkey::find_program_address(&[pda_account.key.as_ref(), b"comment"], program_id);
+    if counter_pda != *pda_counter.key {
+        msg!("Invalid seeds for counter PDA");
+        return Err(ReviewError::InvalidPDA.into());
+    }
+    if pda_counter.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    // Return rent lamports to the authority and zero out both accounts so the
+    // same initializer+title can create a fresh review (and counter) later,
+    // the same way the SPL token program closes accounts
+    msg!("Returning rent lamports and closing account");
+    let dest_starting_lamports = authority.lamports();
+    **authority.lamports.borrow_mut() = dest_starting_lamports
+        .checked_add(pda_account.lamports())
+        .and_then(|sum| sum.checked_add(pda_counter.lamports()))
+        .ok_or(ProgramError::InvalidAccountData)?;
+    **pda_account.lamports.borrow_mut() = 0;
+    **pda_counter.lamports.borrow_mut() = 0;
+    pda_account.realloc(0, false)?;
+    pda_counter.realloc(0, false)?;
+
+    Ok(())
+}
+
+/// Requires `authority_info` to be both the expected review authority and a signer.
+pub fn check_authority(authority_info: &AccountInfo, expected: &Pubkey) -> ProgramResult {
+    if authority_info.key != expected {
+        msg!("Incorrect review authority");
+        return Err(ReviewError::IncorrectAuthority.into());
+    }
+    if !authority_info.is_signer {
+        msg!("Missing required signature");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+pub fn set_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Pubkey,
+) -> ProgramResult {
+    msg!("Setting new review authority...");
+
+    let account_info_iter = &mut accounts.iter();
+    let authority = next_account_info(account_info_iter)?;
+    let pda_account = next_account_info(account_info_iter)?;
+
+    if pda_account.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut account_data = MovieAccountState::try_from_slice(&pda_account.data.borrow())?;
+    if account_data.discriminator != MOVIE_ACCOUNT_DISCRIMINATOR {
+        msg!("Account is not a movie review");
+        return Err(ReviewError::InvalidAccountType.into());
+    }
+
+    check_authority(authority, &account_data.authority)?;
+
+    account_data.authority = new_authority;
+    account_data.serialize(&mut &mut pda_account.data.borrow_mut()[..])?;
+    msg!("Authority updated");
+
+    Ok(())
+}
+
+pub fn add_comment(program_id: &Pubkey, accounts: &[AccountInfo], comment: String) -> ProgramResult {
+    msg!("Adding comment...");
+
+    let account_info_iter = &mut accounts.iter();
+    let commenter = next_account_info(account_info_iter)?;
+    let pda_review = next_account_info(account_info_iter)?;
+    let pda_counter = next_account_info(account_info_iter)?;
+    let pda_comment = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    // Validate signer
+    if !commenter.is_signer {
+        msg!("Missing required signature");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate the counter PDA
+    let (counter_pda, _counter_bump) =
+        Pubkey::find_program_address(&[pda_review.key.as_ref(), b"comment"], program_id);
+    if counter_pda != *pda_counter.key {
+        msg!("Invalid seeds for counter PDA");
+        return Err(ReviewError::InvalidPDA.into());
+    }
+
+    let mut counter_data = MovieCommentCounter::try_from_slice(&pda_counter.data.borrow())?;
+    if counter_data.discriminator != MOVIE_COMMENT_COUNTER_DISCRIMINATOR {
+        msg!("Account is not a comment counter");
+        return Err(ReviewError::InvalidAccountType.into());
+    }
+    if !counter_data.is_initialized() {
+        msg!("Comment counter account is not initialized");
+        return Err(ReviewError::UninitializedAccount.into());
+    }
+
+    // Derive and validate the comment PDA for the current counter value
+    let (comment_pda, comment_bump) = Pubkey::find_program_address(
+        &[
+            pda_review.key.as_ref(),
+            counter_data.counter.to_le_bytes().as_ref(),
+        ],
+        program_id,
+    );
+    if comment_pda != *pda_comment.key {
+        msg!("Invalid seeds for comment PDA");
+        return Err(ReviewError::InvalidPDA.into());
+    }
+
+    let account_len = MovieComment::get_account_size(comment.clone());
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(account_len);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            commenter.key,
+            pda_comment.key,
+            rent_lamports,
+            account_len.try_into().unwrap(),
+            program_id,
+        ),
+        &[
+            commenter.clone(),
+            pda_comment.clone(),
+            system_program.clone(),
+        ],
+        &[&[
+            pda_review.key.as_ref(),
+            counter_data.counter.to_le_bytes().as_ref(),
+            &[comment_bump],
+        ]],
+    )?;
+
+    msg!("Comment account created");
+
+    let comment_data = MovieComment {
+        discriminator: MOVIE_COMMENT_DISCRIMINATOR,
+        is_initialized: true,
+        review: *pda_review.key,
+        commenter: *commenter.key,
+        comment,
+        count: counter_data.counter,
+    };
+    comment_data.serialize(&mut &mut pda_comment.data.borrow_mut()[..])?;
+
+    msg!("Comment count: {}", counter_data.counter);
+    counter_data.counter += 1;
+    counter_data.serialize(&mut &mut pda_counter.data.borrow_mut()[..])?;
+
     Ok(())
 }
\ No newline at end of file
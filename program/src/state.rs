@@ -4,10 +4,18 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+// Values are chosen outside {0, 1} so they can never collide with a leading
+// `is_initialized: bool` byte from another account type.
+pub const MOVIE_ACCOUNT_DISCRIMINATOR: u8 = 10;
+pub const MOVIE_COMMENT_COUNTER_DISCRIMINATOR: u8 = 20;
+pub const MOVIE_COMMENT_DISCRIMINATOR: u8 = 30;
+
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct MovieAccountState {
+    pub discriminator: u8,
     pub is_initialized: bool,
     pub reviewer: Pubkey,
+    pub authority: Pubkey,
     pub rating: u8,
     pub title: String,
     pub description: String,
@@ -17,6 +25,8 @@ impl MovieAccountState {
 
     pub fn get_account_size(title: String, description: String) -> usize {
         return 1
+            + 1
+            + 32
             + 32
             + 1
             + (4 + title.len())
@@ -30,4 +40,47 @@ impl IsInitialized for MovieAccountState {
     fn is_initialized(&self) -> bool {
         self.is_initialized
     }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct MovieCommentCounter {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub counter: u64,
+}
+
+impl MovieCommentCounter {
+    pub const SIZE: usize = 1 + 1 + 8;
+}
+
+impl Sealed for MovieCommentCounter {}
+
+impl IsInitialized for MovieCommentCounter {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct MovieComment {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub review: Pubkey,
+    pub commenter: Pubkey,
+    pub comment: String,
+    pub count: u64,
+}
+
+impl MovieComment {
+    pub fn get_account_size(comment: String) -> usize {
+        return 1 + 1 + 32 + 32 + (4 + comment.len()) + 8;
+    }
+}
+
+impl Sealed for MovieComment {}
+
+impl IsInitialized for MovieComment {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
 }
\ No newline at end of file